@@ -1,12 +1,84 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![feature(allocator_api)]
+#![feature(try_reserve_kind)]
 
-use alloc::{Global, Layout};
-use ptr::NonNull;
-use std::{alloc, fmt, mem, ptr};
-use std::alloc::Allocator;
-use std::cmp::max;
-use std::fmt::{Formatter, Write};
-use std::ops::Add;
+extern crate alloc as alloc_crate;
+#[cfg(test)]
+extern crate std;
+
+use alloc_crate::alloc::Global;
+use alloc_crate::boxed::Box;
+use alloc_crate::collections::{TryReserveError, TryReserveErrorKind};
+use alloc_crate::vec::Vec;
+use core::alloc::{Allocator, Layout};
+use core::cmp::max;
+use core::fmt::{self, Formatter, Write};
+use core::mem::{self, MaybeUninit};
+use core::ops::Add;
+use core::ptr::{self, NonNull};
+
+/// Maps a logical index into a physical offset in the backing storage of a
+/// buffer whose live elements straddle a gap of `gap_len` elements starting
+/// at `gap_start`, or `None` if `i` is past `len`.
+fn gap_offset(i: usize, len: usize, gap_start: usize, gap_len: usize) -> Option<usize> {
+    if i >= len {
+        None
+    } else if i < gap_start {
+        Some(i)
+    } else {
+        Some(i + gap_len)
+    }
+}
+
+/// Moves a `gap_len`-element gap currently at `*gap_start` within `buffer`
+/// so it starts at `i` instead, shifting whichever run of live elements sits
+/// between the old and new position across it. Shared by every gap-buffer
+/// flavor in this crate so a fix to the move itself can't miss one of them.
+///
+/// # Safety
+///
+/// `buffer` must be valid for reads and writes of the `max(i, *gap_start) +
+/// gap_len` elements the move touches.
+unsafe fn gap_move_to<T>(buffer: *mut T, gap_start: &mut usize, gap_len: usize, i: usize) {
+    if i != *gap_start {
+        if i < *gap_start {
+            unsafe {
+                let src = buffer.add(i);
+                let dst = buffer.add(i).add(gap_len);
+                ptr::copy(src, dst, *gap_start - i)
+            }
+        } else {
+            unsafe {
+                let src = buffer.add(*gap_start).add(gap_len);
+                let dst = buffer.add(*gap_start);
+                ptr::copy(src, dst, i - *gap_start)
+            }
+        }
+        *gap_start = i;
+    }
+}
+
+/// The read surface shared by every gap-buffer flavor in this crate,
+/// whether backed by an allocator ([`GapBuffer`]) or inline storage
+/// ([`ArrayGapBuffer`]).
+pub trait GapSequence<T> {
+    fn len(&self) -> usize;
+
+    fn capacity(&self) -> usize;
+
+    fn get(&self, i: usize) -> Option<&T>;
+
+    fn as_slices(&self) -> (&[T], &[T]);
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter { front, back }
+    }
+}
 
 pub struct GapBuffer<T, A: Allocator = Global> {
     allocator: A,
@@ -48,16 +120,8 @@ impl<T, A: Allocator> GapBuffer<T, A> {
     }
 
     pub fn get(&self, i: usize) -> Option<&T> {
-        if i >= self.len() {
-            None
-        } else {
-            let offset = if i < self.gap_start {
-                i
-            } else {
-                i + self.gap_start + self.gap_len
-            };
-            Some(unsafe { &*self.buffer.as_ptr().add(offset) })
-        }
+        let offset = gap_offset(i, self.len(), self.gap_start, self.gap_len)?;
+        Some(unsafe { &*self.buffer.as_ptr().add(offset) })
     }
 
     pub fn push(&mut self, value: T) {
@@ -65,14 +129,44 @@ impl<T, A: Allocator> GapBuffer<T, A> {
     }
 
     pub fn insert(&mut self, i: usize, value: T) {
+        self.try_insert(i, value).unwrap()
+    }
+
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        self.try_insert(self.len(), value)
+    }
+
+    pub fn try_insert(&mut self, i: usize, value: T) -> Result<(), TryReserveError> {
         if i > self.len() {
             panic!("Index out of bound for {:?} of buffer's size {:?}", i, self.len())
         }
         self.gap_move_to(i);
-        self.gap_ensure_size(1);
+        self.gap_try_ensure_size(1)?;
         unsafe { self.buffer.as_ptr().add(self.gap_start).write(value) }
         self.gap_start += 1;
         self.gap_len -= 1;
+        Ok(())
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.gap_try_ensure_size(additional)
+    }
+
+    pub fn insert_slice(&mut self, i: usize, slice: &[T])
+    where
+        T: Clone,
+    {
+        if i > self.len() {
+            panic!("Index out of bound for {:?} of buffer's size {:?}", i, self.len())
+        }
+        self.gap_move_to(i);
+        self.gap_ensure_size(slice.len());
+        let dst = self.buffer.as_ptr();
+        for (offset, value) in slice.iter().enumerate() {
+            unsafe { dst.add(self.gap_start).add(offset).write(value.clone()) }
+        }
+        self.gap_start += slice.len();
+        self.gap_len -= slice.len();
     }
 
     pub fn delete(&mut self, i: usize) -> T {
@@ -84,6 +178,56 @@ impl<T, A: Allocator> GapBuffer<T, A> {
         unsafe { self.buffer.as_ptr().add(self.gap_start).add(self.gap_len - 1).read() }
     }
 
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter { front, back }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut { front, back }
+    }
+
+    pub fn cursor_at(&mut self, i: usize) -> Cursor<'_, T, A> {
+        if i > self.len() {
+            panic!("Index out of bound for {:?} of buffer's size {:?}", i, self.len())
+        }
+        self.gap_move_to(i);
+        Cursor { buffer: self }
+    }
+
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr = self.buffer.as_ptr();
+        unsafe {
+            (
+                core::slice::from_raw_parts(ptr, self.gap_start),
+                core::slice::from_raw_parts(
+                    ptr.add(self.gap_start).add(self.gap_len),
+                    self.buffer_capacity - self.gap_start - self.gap_len,
+                ),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let ptr = self.buffer.as_ptr();
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(ptr, self.gap_start),
+                core::slice::from_raw_parts_mut(
+                    ptr.add(self.gap_start).add(self.gap_len),
+                    self.buffer_capacity - self.gap_start - self.gap_len,
+                ),
+            )
+        }
+    }
+
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let len = self.len();
+        self.gap_move_to(len);
+        unsafe { core::slice::from_raw_parts_mut(self.buffer.as_ptr(), len) }
+    }
+
     pub fn len(&self) -> usize {
         self.buffer_capacity - self.gap_len
     }
@@ -93,49 +237,49 @@ impl<T, A: Allocator> GapBuffer<T, A> {
     }
 
     fn gap_move_to(&mut self, i: usize) {
-        if i != self.gap_start {
-            let buffer = self.buffer.as_ptr();
-            if i < self.gap_start {
-                unsafe {
-                    let src = buffer.add(i);
-                    let dst = buffer.add(i).add(self.gap_len);
-                    ptr::copy(src, dst, self.gap_start - i)
-                }
-            } else {
-                unsafe {
-                    let src = buffer.add(self.gap_start).add(self.gap_len);
-                    let dst = buffer.add(self.gap_start);
-                    ptr::copy(src, dst, i - self.gap_start)
-                }
-            }
-            self.gap_start = i;
-        }
+        unsafe { gap_move_to(self.buffer.as_ptr(), &mut self.gap_start, self.gap_len, i) }
     }
 
     fn gap_ensure_size(&mut self, size: usize) {
+        self.gap_try_ensure_size(size).unwrap()
+    }
+
+    fn gap_try_ensure_size(&mut self, size: usize) -> Result<(), TryReserveError> {
         if self.gap_len < size {
-            let new_capacity = max(self.buffer_capacity * 2, size);
+            let needed = self
+                .len()
+                .checked_add(size)
+                .ok_or_else(|| TryReserveError::from(TryReserveErrorKind::CapacityOverflow))?;
+            let new_capacity = max(self.buffer_capacity * 2, needed);
             let new_capacity = max(new_capacity, GapBuffer::<T>::MIN_NON_ZERO_CAP);
             let new_gap_len = new_capacity - self.len();
-            let new_layout = Layout::array::<T>(new_capacity).unwrap();
+            let suffix_len = self.len() - self.gap_start;
+            let new_layout = Layout::array::<T>(new_capacity)
+                .map_err(|_| TryReserveError::from(TryReserveErrorKind::CapacityOverflow))?;
             let new_buffer = if let Some(old_layout) = self.buffer_layout() {
-                unsafe {
-                    self.allocator.grow(self.buffer.cast(), old_layout, new_layout).unwrap().cast()
-                }
+                unsafe { self.allocator.grow(self.buffer.cast(), old_layout, new_layout) }
             } else {
-                self.allocator.allocate(new_layout).unwrap().cast()
-            };
+                self.allocator.allocate(new_layout)
+            }
+            .map_err(|_| {
+                TryReserveError::from(TryReserveErrorKind::AllocError { layout: new_layout, non_exhaustive: () })
+            })?
+            .cast();
             self.buffer = new_buffer;
+            if suffix_len > 0 {
+                // The tail segment after the gap is anchored to the end of the old
+                // allocation; shift it to the end of the newly grown one.
+                unsafe {
+                    let ptr = self.buffer.as_ptr();
+                    let src = ptr.add(self.gap_start + self.gap_len);
+                    let dst = ptr.add(self.gap_start + new_gap_len);
+                    ptr::copy(src, dst, suffix_len);
+                }
+            }
             self.buffer_capacity = new_capacity;
             self.gap_len = new_gap_len;
         }
-    }
-
-    fn buffer_extend_from_vec(&mut self, vec: Vec<T>) {
-        self.gap_ensure_size(vec.len());
-        for value in vec {
-            self.push(value)
-        }
+        Ok(())
     }
 
     fn buffer_layout(&self) -> Option<Layout> {
@@ -147,6 +291,226 @@ impl<T, A: Allocator> GapBuffer<T, A> {
     }
 }
 
+impl<T, A: Allocator> GapSequence<T> for GapBuffer<T, A> {
+    fn len(&self) -> usize {
+        GapBuffer::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        GapBuffer::capacity(self)
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        GapBuffer::get(self, i)
+    }
+
+    fn as_slices(&self) -> (&[T], &[T]) {
+        GapBuffer::as_slices(self)
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        GapBuffer::iter(self)
+    }
+}
+
+pub struct Iter<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((first, rest)) = self.front.split_first() {
+            self.front = rest;
+            Some(first)
+        } else if let Some((first, rest)) = self.back.split_first() {
+            self.back = rest;
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((last, rest)) = self.back.split_last() {
+            self.back = rest;
+            Some(last)
+        } else if let Some((last, rest)) = self.front.split_last() {
+            self.front = rest;
+            Some(last)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+pub struct IterMut<'a, T> {
+    front: &'a mut [T],
+    back: &'a mut [T],
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let front = mem::take(&mut self.front);
+        if let Some((first, rest)) = front.split_first_mut() {
+            self.front = rest;
+            Some(first)
+        } else {
+            let back = mem::take(&mut self.back);
+            if let Some((first, rest)) = back.split_first_mut() {
+                self.back = rest;
+                Some(first)
+            } else {
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let back = mem::take(&mut self.back);
+        if let Some((last, rest)) = back.split_last_mut() {
+            self.back = rest;
+            Some(last)
+        } else {
+            let front = mem::take(&mut self.front);
+            if let Some((last, rest)) = front.split_last_mut() {
+                self.front = rest;
+                Some(last)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    buffer: GapBuffer<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.len() == 0 {
+            None
+        } else {
+            Some(self.buffer.delete(0))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.buffer.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.buffer.len();
+        if len == 0 {
+            None
+        } else {
+            Some(self.buffer.delete(len - 1))
+        }
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for GapBuffer<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { buffer: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a GapBuffer<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut GapBuffer<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A cursor into a [`GapBuffer`] that parks the gap at the cursor's
+/// position, so consecutive edits at that position don't pay repeated
+/// gap-move cost. The gap only moves again when the cursor itself moves.
+pub struct Cursor<'a, T, A: Allocator = Global> {
+    buffer: &'a mut GapBuffer<T, A>,
+}
+
+impl<'a, T, A: Allocator> Cursor<'a, T, A> {
+    pub fn position(&self) -> usize {
+        self.buffer.gap_start
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.buffer.get(self.position())
+    }
+
+    pub fn insert(&mut self, value: T) {
+        let position = self.position();
+        self.buffer.insert(position, value);
+    }
+
+    pub fn delete_forward(&mut self) -> Option<T> {
+        let position = self.position();
+        if position >= self.buffer.len() {
+            None
+        } else {
+            Some(self.buffer.delete(position))
+        }
+    }
+
+    pub fn delete_backward(&mut self) -> Option<T> {
+        let position = self.position();
+        if position == 0 {
+            None
+        } else {
+            Some(self.buffer.delete(position - 1))
+        }
+    }
+
+    pub fn move_by(&mut self, delta: isize) {
+        let position = (self.position() as isize + delta).clamp(0, self.buffer.len() as isize);
+        self.buffer.gap_move_to(position as usize);
+    }
+}
+
 impl<T, A: Allocator> Drop for GapBuffer<T, A> {
     fn drop(&mut self) {
         unsafe {
@@ -165,6 +529,36 @@ impl<T, A: Allocator> Drop for GapBuffer<T, A> {
     }
 }
 
+impl<T, A: Allocator> Extend<T> for GapBuffer<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.gap_ensure_size(lower);
+        for value in iter {
+            self.push(value)
+        }
+    }
+}
+
+impl<T> FromIterator<T> for GapBuffer<T, Global> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = GapBuffer::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for GapBuffer<T, A> {
+    fn clone(&self) -> Self {
+        let (front, back) = self.as_slices();
+        let mut buffer = GapBuffer::new_with_allocator(self.allocator.clone());
+        buffer.gap_ensure_size(self.len());
+        buffer.insert_slice(0, front);
+        buffer.insert_slice(front.len(), back);
+        buffer
+    }
+}
+
 impl<T> From<GapBuffer<T>> for Box<[T]> {
     fn from(value: GapBuffer<T>) -> Self {
         let mut value = value;
@@ -179,7 +573,7 @@ impl<T> From<GapBuffer<T>> for Box<[T]> {
 impl<T> From<Box<[T]>> for GapBuffer<T> {
     fn from(value: Box<[T]>) -> Self {
         let mut buffer = GapBuffer::<T>::new();
-        buffer.buffer_extend_from_vec(value.into_vec());
+        buffer.extend(value.into_vec());
         buffer
     }
 }
@@ -205,11 +599,202 @@ impl<T: fmt::Debug> fmt::Debug for GapBuffer<T> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, A: Allocator> serde::Serialize for GapBuffer<T, A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for GapBuffer<T, Global> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct GapBufferVisitor<T>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for GapBufferVisitor<T> {
+            type Value = GapBuffer<T, Global>;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence of elements")
+            }
+
+            fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+            where
+                S: serde::de::SeqAccess<'de>,
+            {
+                let mut buffer = GapBuffer::new();
+                buffer.gap_ensure_size(seq.size_hint().unwrap_or(0));
+                while let Some(value) = seq.next_element()? {
+                    buffer.push(value);
+                }
+                Ok(buffer)
+            }
+        }
+
+        deserializer.deserialize_seq(GapBufferVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Error returned by [`ArrayGapBuffer::insert`]/[`ArrayGapBuffer::push`] when
+/// the fixed `N`-element capacity is already full.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("ArrayGapBuffer is at capacity")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// A fixed-capacity gap buffer that stores its `N` elements inline and never
+/// allocates, for use on targets without a heap allocator. `insert`/`push`
+/// return [`CapacityError`] instead of growing once `N` elements are live.
+pub struct ArrayGapBuffer<T, const N: usize> {
+    storage: MaybeUninit<[T; N]>,
+    gap_start: usize,
+    gap_len: usize,
+}
+
+impl<T, const N: usize> ArrayGapBuffer<T, N> {
+    pub fn new() -> Self {
+        Self {
+            storage: MaybeUninit::uninit(),
+            gap_start: 0,
+            gap_len: N,
+        }
+    }
+
+    pub fn get(&self, i: usize) -> Option<&T> {
+        let offset = gap_offset(i, self.len(), self.gap_start, self.gap_len)?;
+        Some(unsafe { &*self.buffer_ptr().add(offset) })
+    }
+
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError> {
+        self.insert(self.len(), value)
+    }
+
+    pub fn insert(&mut self, i: usize, value: T) -> Result<(), CapacityError> {
+        if i > self.len() {
+            panic!("Index out of bound for {:?} of buffer's size {:?}", i, self.len())
+        }
+        if self.gap_len == 0 {
+            return Err(CapacityError);
+        }
+        self.gap_move_to(i);
+        unsafe { self.buffer_ptr_mut().add(self.gap_start).write(value) }
+        self.gap_start += 1;
+        self.gap_len -= 1;
+        Ok(())
+    }
+
+    pub fn delete(&mut self, i: usize) -> T {
+        if i >= self.len() {
+            panic!("Index out of bound for {:?} of buffer's size {:?}", i, self.len())
+        }
+        self.gap_move_to(i);
+        self.gap_len += 1;
+        unsafe { self.buffer_ptr_mut().add(self.gap_start).add(self.gap_len - 1).read() }
+    }
+
+    pub fn len(&self) -> usize {
+        N - self.gap_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr = self.buffer_ptr();
+        unsafe {
+            (
+                core::slice::from_raw_parts(ptr, self.gap_start),
+                core::slice::from_raw_parts(ptr.add(self.gap_start).add(self.gap_len), N - self.gap_start - self.gap_len),
+            )
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter { front, back }
+    }
+
+    fn buffer_ptr(&self) -> *const T {
+        self.storage.as_ptr().cast()
+    }
+
+    fn buffer_ptr_mut(&mut self) -> *mut T {
+        self.storage.as_mut_ptr().cast()
+    }
+
+    fn gap_move_to(&mut self, i: usize) {
+        let buffer = self.buffer_ptr_mut();
+        unsafe { gap_move_to(buffer, &mut self.gap_start, self.gap_len, i) }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayGapBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayGapBuffer<T, N> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = self.buffer_ptr_mut();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, self.gap_start));
+
+            let ptr = ptr.add(self.gap_start).add(self.gap_len);
+            let len = N - self.gap_start - self.gap_len;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, len));
+        }
+    }
+}
+
+impl<T, const N: usize> GapSequence<T> for ArrayGapBuffer<T, N> {
+    fn len(&self) -> usize {
+        ArrayGapBuffer::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        ArrayGapBuffer::capacity(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        ArrayGapBuffer::is_empty(self)
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        ArrayGapBuffer::get(self, i)
+    }
+
+    fn as_slices(&self) -> (&[T], &[T]) {
+        ArrayGapBuffer::as_slices(self)
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        ArrayGapBuffer::iter(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::rc::Rc;
 
-    use crate::GapBuffer;
+    use crate::{ArrayGapBuffer, CapacityError, GapBuffer, GapSequence};
 
     #[test]
     fn gap_buffer_with_capacity_8() {
@@ -243,6 +828,19 @@ mod tests {
         assert_eq!(Some(&3u8), buf.get(2));
     }
 
+    #[test]
+    fn gap_buffer_get_across_gap() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        assert_eq!(Some(&1u8), buf.get(0));
+        assert_eq!(Some(&9u8), buf.get(1));
+        assert_eq!(Some(&2u8), buf.get(2));
+        assert_eq!(Some(&3u8), buf.get(3));
+    }
+
     #[test]
     fn gap_buffer_push() {
         let mut buf = GapBuffer::<u8>::new();
@@ -292,6 +890,176 @@ mod tests {
         assert_eq!(buf.buffer_capacity, 128);
     }
 
+    #[test]
+    fn gap_buffer_iter() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        let values: Vec<&u8> = buf.iter().collect();
+        assert_eq!(vec![&1, &9, &2, &3], values);
+        assert_eq!(4, buf.iter().len());
+    }
+
+    #[test]
+    fn gap_buffer_iter_rev() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        let values: Vec<&u8> = buf.iter().rev().collect();
+        assert_eq!(vec![&3, &2, &1], values);
+    }
+
+    #[test]
+    fn gap_buffer_iter_mut() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        for value in buf.iter_mut() {
+            *value += 1;
+        }
+        assert_eq!(vec![&2, &3, &4], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_into_iter() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        let values: Vec<u8> = buf.into_iter().collect();
+        assert_eq!(vec![1, 9, 2, 3], values);
+    }
+
+    #[test]
+    fn gap_buffer_into_iter_drops_remaining() {
+        let last = Rc::new(0);
+        let weak = Rc::downgrade(&last);
+        {
+            let mut buf = GapBuffer::new();
+            buf.push(Rc::new(1));
+            buf.push(last);
+            let mut into_iter = buf.into_iter();
+            into_iter.next();
+        };
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn gap_buffer_as_slices() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        let (front, back) = buf.as_slices();
+        assert_eq!(&[1, 9], front);
+        assert_eq!(&[2, 3], back);
+    }
+
+    #[test]
+    fn gap_buffer_as_mut_slices() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        {
+            let (front, back) = buf.as_mut_slices();
+            front[0] += 10;
+            back[0] += 10;
+        }
+        assert_eq!(vec![&11, &9, &12, &3], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_make_contiguous() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        assert_eq!(&[1, 9, 2, 3], buf.make_contiguous());
+        let (front, back) = buf.as_slices();
+        assert_eq!(&[1, 9, 2, 3], front);
+        assert!(back.is_empty());
+    }
+
+    #[test]
+    fn gap_buffer_try_reserve() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.try_reserve(32).unwrap();
+        assert_eq!(32, buf.gap_len);
+        assert_eq!(32, buf.buffer_capacity);
+    }
+
+    #[test]
+    fn gap_buffer_try_reserve_accounts_for_existing_len() {
+        let mut buf = GapBuffer::<u8>::new();
+        for i in 0..100 {
+            buf.push(i);
+        }
+        buf.try_reserve(1000).unwrap();
+        assert!(buf.gap_len >= 1000);
+        assert!(buf.buffer_capacity >= buf.len() + 1000);
+        assert_eq!(100, buf.len());
+    }
+
+    #[test]
+    fn gap_buffer_try_insert_and_push() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.try_push(1).unwrap();
+        buf.try_push(2).unwrap();
+        buf.try_insert(1, 9).unwrap();
+        assert_eq!(vec![&1, &9, &2], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_cursor_insert() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        let mut cursor = buf.cursor_at(1);
+        cursor.insert(7);
+        cursor.insert(8);
+        assert_eq!(3, cursor.position());
+        assert_eq!(vec![&1, &7, &8, &2, &3], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_cursor_delete() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        let mut cursor = buf.cursor_at(2);
+        assert_eq!(Some(2), cursor.delete_backward());
+        assert_eq!(1, cursor.position());
+        assert_eq!(Some(3), cursor.delete_forward());
+        assert_eq!(vec![&1], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_cursor_peek_and_move() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        let mut cursor = buf.cursor_at(0);
+        assert_eq!(Some(&1), cursor.peek());
+        cursor.move_by(2);
+        assert_eq!(Some(&3), cursor.peek());
+        cursor.move_by(1);
+        assert_eq!(None, cursor.peek());
+        cursor.move_by(-5);
+        assert_eq!(0, cursor.position());
+    }
+
     #[test]
     fn gap_buffer_drop_test() {
         let last = Rc::new(0);
@@ -302,4 +1070,107 @@ mod tests {
         };
         assert!(weak.upgrade().is_none());
     }
+
+    #[test]
+    fn array_gap_buffer_push_and_insert() {
+        let mut buf = ArrayGapBuffer::<u8, 4>::new();
+        buf.push(1).unwrap();
+        buf.push(2).unwrap();
+        buf.insert(1, 9).unwrap();
+        assert_eq!(vec![&1, &9, &2], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn array_gap_buffer_full_returns_capacity_error() {
+        let mut buf = ArrayGapBuffer::<u8, 2>::new();
+        buf.push(1).unwrap();
+        buf.push(2).unwrap();
+        assert_eq!(Err(CapacityError), buf.push(3));
+    }
+
+    #[test]
+    fn array_gap_buffer_shares_gap_sequence_surface() {
+        let mut buf = ArrayGapBuffer::<u8, 4>::new();
+        buf.push(1).unwrap();
+        buf.push(2).unwrap();
+        buf.push(3).unwrap();
+        fn sum<T: Copy + Into<u32>>(seq: &impl GapSequence<T>) -> u32 {
+            seq.iter().map(|&v| v.into()).sum()
+        }
+        assert_eq!(6, sum(&buf));
+    }
+
+    #[test]
+    fn array_gap_buffer_drop_test() {
+        let last = Rc::new(0);
+        let weak = Rc::downgrade(&last);
+        {
+            let mut buf = ArrayGapBuffer::<_, 1>::new();
+            buf.push(last).unwrap();
+        };
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn gap_buffer_insert_slice() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(4);
+        buf.insert_slice(1, &[2, 3]);
+        assert_eq!(vec![&1, &2, &3, &4], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_insert_slice_grows_non_empty_buffer() {
+        let mut buf = GapBuffer::<u32>::new();
+        for i in 0..100 {
+            buf.push(i);
+        }
+        let big_slice: Vec<u32> = (1000..2000).collect();
+        buf.insert_slice(50, &big_slice);
+        assert_eq!(1100, buf.len());
+        let values: Vec<u32> = buf.iter().copied().collect();
+        assert_eq!(values[..50], (0..50).collect::<Vec<_>>()[..]);
+        assert_eq!(values[50..1050], big_slice[..]);
+        assert_eq!(values[1050..], (50..100).collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn gap_buffer_extend() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.extend(vec![2, 3, 4]);
+        assert_eq!(vec![&1, &2, &3, &4], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_from_iterator() {
+        let buf: GapBuffer<u8> = (1..=3).collect();
+        assert_eq!(vec![&1, &2, &3], buf.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gap_buffer_clone() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        let clone = buf.clone();
+        assert_eq!(buf.iter().collect::<Vec<_>>(), clone.iter().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gap_buffer_serde_round_trip() {
+        let mut buf = GapBuffer::<u8>::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        buf.insert(1, 9);
+        let json = serde_json::to_string(&buf).unwrap();
+        assert_eq!("[1,9,2,3]", json);
+        let round_tripped: GapBuffer<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(buf.iter().collect::<Vec<_>>(), round_tripped.iter().collect::<Vec<_>>());
+    }
 }
\ No newline at end of file